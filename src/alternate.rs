@@ -6,11 +6,12 @@ pub struct Alternate<A, B> {
     b: B,
     odd: bool,
     done: bool,
+    trimmed: bool,
 }
 
 impl<
-    A: Iterator<Item = T>, 
-    B: Iterator<Item = T>, 
+    A: Iterator<Item = T>,
+    B: Iterator<Item = T>,
     T
     > Alternate<A, B> {
     pub(crate) fn new(a: A, b: B) -> Self {
@@ -19,10 +20,23 @@ impl<
             b,
             odd: false,
             done: false,
+            trimmed: false,
         }
     }
 }
 
+/// Returns the number of items an `Alternate` still yields from the front, given the remaining
+/// lengths of both sides and which side takes the next turn. Iteration short-circuits at the first
+/// side to run dry, so the side that goes first contributes at most one more item than the other.
+fn remaining_len(first: usize, second: usize, first_goes: bool) -> usize {
+    let (head, tail) = if first_goes { (first, second) } else { (second, first) };
+    if head <= tail {
+        2 * head
+    } else {
+        2 * tail + 1
+    }
+}
+
 impl<A: Iterator<Item = T>, B: Iterator<Item = T>, T> Iterator for Alternate<A, B> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
@@ -37,8 +51,85 @@ impl<A: Iterator<Item = T>, B: Iterator<Item = T>, T> Iterator for Alternate<A,
         if item.is_none() {
             self.done = true;
         }
-        
+
         self.odd = !self.odd;
         item
     }
-}
\ No newline at end of file
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (a_low, a_high) = self.a.size_hint();
+        let (b_low, b_high) = self.b.size_hint();
+        let low = remaining_len(a_low, b_low, !self.odd);
+        let high = match (a_high, b_high) {
+            (Some(a_high), Some(b_high)) => Some(remaining_len(a_high, b_high, !self.odd)),
+            _ => None,
+        };
+        (low, high)
+    }
+}
+
+impl<A, B, T> DoubleEndedIterator for Alternate<A, B>
+where
+    A: ExactSizeIterator<Item = T> + DoubleEndedIterator,
+    B: ExactSizeIterator<Item = T> + DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done { return None }
+
+        // On first use from the back, drop the tail the short-circuit would never have reached so
+        // that the two sides interleave exactly and the ends can meet in the middle.
+        if !self.trimmed {
+            let (la, lb) = (self.a.len(), self.b.len());
+            let first_goes_a = !self.odd;
+            let n = remaining_len(la, lb, first_goes_a);
+            let (a_yield, b_yield) = if first_goes_a {
+                ((n + 1) / 2, n / 2)
+            } else {
+                (n / 2, (n + 1) / 2)
+            };
+            for _ in 0..la - a_yield {
+                self.a.next_back();
+            }
+            for _ in 0..lb - b_yield {
+                self.b.next_back();
+            }
+            self.trimmed = true;
+        }
+
+        let (la, lb) = (self.a.len(), self.b.len());
+        let total = la + lb;
+        if total == 0 {
+            self.done = true;
+            return None;
+        }
+
+        // After trimming the sides interleave fully starting with the side whose turn is next from
+        // the front, so the parity of the total length tells us which side the last item is on.
+        let first_is_a = !self.odd;
+        let last_is_first = (total - 1) % 2 == 0;
+        let last_is_a = if last_is_first { first_is_a } else { !first_is_a };
+
+        if last_is_a {
+            self.a.next_back()
+        } else {
+            self.b.next_back()
+        }
+    }
+}
+
+impl<A, B, T> ExactSizeIterator for Alternate<A, B>
+where
+    A: ExactSizeIterator<Item = T>,
+    B: ExactSizeIterator<Item = T>,
+{
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            remaining_len(self.a.len(), self.b.len(), !self.odd)
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use core::fmt;
+
+/// An iterator that merges runs of adjacent items using a closure.
+///
+/// See [`ExoticIteratorExt::coalesce`](crate::ExoticIteratorExt::coalesce) for details.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Coalesce<I: Iterator, F> {
+    iter: I,
+    last: Option<I::Item>,
+    f: F,
+    done: bool,
+}
+
+impl<I: Iterator, F> Coalesce<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            last: None,
+            f,
+            done: false,
+        }
+    }
+}
+
+impl<I, F> Iterator for Coalesce<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut acc = match self.last.take().or_else(|| self.iter.next()) {
+            Some(acc) => acc,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+
+        loop {
+            match self.iter.next() {
+                Some(next) => match (self.f)(acc, next) {
+                    Ok(merged) => acc = merged,
+                    Err((a, b)) => {
+                        self.last = Some(b);
+                        return Some(a);
+                    }
+                },
+                None => {
+                    self.done = true;
+                    return Some(acc);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, high) = self.iter.size_hint();
+        let pending = usize::from(self.last.is_some());
+        // Every emitted item consumes at least one input, so the run could collapse to nothing more
+        // than the accumulator; only the upper bound is known.
+        (0, high.map(|high| high + pending))
+    }
+}
+
+impl<I, F> fmt::Debug for Coalesce<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Coalesce")
+            .field("iter", &self.iter)
+            .field("last", &self.last)
+            .finish_non_exhaustive()
+    }
+}
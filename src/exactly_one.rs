@@ -0,0 +1,24 @@
+use std::error::Error;
+use std::fmt;
+
+/// The error returned by [`ExoticIteratorExt::exactly_one`](crate::ExoticIteratorExt::exactly_one)
+/// and [`ExoticIteratorExt::the_one`](crate::ExoticIteratorExt::the_one) when the iterator did not
+/// yield exactly one matching item.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExactlyOneError<T> {
+    /// No item matched.
+    Zero,
+    /// More than one item matched; the first two offending items are carried for diagnostics.
+    MoreThanOne(T, T),
+}
+
+impl<T> fmt::Display for ExactlyOneError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExactlyOneError::Zero => f.write_str("expected exactly one matching item, but found none"),
+            ExactlyOneError::MoreThanOne(..) => f.write_str("expected exactly one matching item, but found more than one"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> Error for ExactlyOneError<T> {}
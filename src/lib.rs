@@ -1,6 +1,15 @@
 mod alternate;
+mod coalesce;
+mod exactly_one;
+mod round_robin;
 
 pub use alternate::*;
+pub use coalesce::*;
+pub use exactly_one::*;
+pub use round_robin::*;
+
+use std::collections::HashMap;
+use std::hash::Hash;
 
 /// Provides additional convenience methods to the `Iterator` trait and its implementors.
 pub trait ExoticIteratorExt: Iterator + Sized {
@@ -100,6 +109,156 @@ pub trait ExoticIteratorExt: Iterator + Sized {
     /// assert_eq!(None, iter.next());
     /// ```
     fn alternate<U: IntoIterator<Item = Self::Item>>(self, other: U) -> Alternate<Self, U::IntoIter>;
+
+    /// Creates an iterator that cycles through `self` and `others`, yielding one item from each in
+    /// turn, with `self` providing the first value.
+    ///
+    /// By default the adapter short-circuits at the first `None`, matching [`alternate`](Self::alternate);
+    /// call [`RoundRobin::exhausting`] on the result to instead keep going until every input is drained.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// let ones = vec![1, 1];
+    /// let twos = vec![2, 2];
+    /// let threes = vec![3, 3];
+    /// let items: Vec<_> = ones.into_iter().round_robin(vec![twos.into_iter(), threes.into_iter()]).collect();
+    /// assert_eq!(vec![1, 2, 3, 1, 2, 3], items);
+    /// ```
+    fn round_robin<U: IntoIterator<Item = Self>>(self, others: U) -> RoundRobin<Self>;
+
+    /// Consumes the iterator and returns every item tying for the maximum key, as computed by `f`,
+    /// in the order they appeared. Unlike [`Iterator::max_by_key`], which keeps only the last of any
+    /// ties, this keeps them all. Returns an empty `Vec` for an empty iterator.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// let words = vec!["a", "bb", "cc", "d", "ee"];
+    /// assert_eq!(vec!["bb", "cc", "ee"], words.into_iter().max_set_by_key(|s| s.len()));
+    /// ```
+    fn max_set_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(self, f: F) -> Vec<Self::Item>;
+
+    /// Consumes the iterator and returns every item tying for the minimum key, as computed by `f`,
+    /// in the order they appeared. Returns an empty `Vec` for an empty iterator.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// let words = vec!["aa", "b", "cc", "d", "eee"];
+    /// assert_eq!(vec!["b", "d"], words.into_iter().min_set_by_key(|s| s.len()));
+    /// ```
+    fn min_set_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(self, f: F) -> Vec<Self::Item>;
+
+    /// Consumes the iterator and returns every item tying for the maximum according to `compare`,
+    /// in the order they appeared. Returns an empty `Vec` for an empty iterator.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// let nums = vec![1, 3, 2, 3, 1];
+    /// assert_eq!(vec![3, 3], nums.into_iter().max_set_by(|a, b| a.cmp(b)));
+    /// ```
+    fn max_set_by<F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering>(self, compare: F) -> Vec<Self::Item>;
+
+    /// Consumes the iterator and returns every item tying for the minimum according to `compare`,
+    /// in the order they appeared. Returns an empty `Vec` for an empty iterator.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// let nums = vec![2, 1, 3, 1, 2];
+    /// assert_eq!(vec![1, 1], nums.into_iter().min_set_by(|a, b| a.cmp(b)));
+    /// ```
+    fn min_set_by<F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering>(self, compare: F) -> Vec<Self::Item>;
+
+    /// Consumes the iterator and tallies how many items fall into each bucket, where an item's
+    /// bucket is the key produced for it by `key`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// let counts = "mississippi".chars().counts_by(|c| *c);
+    /// assert_eq!(Some(&4), counts.get(&'s'));
+    /// assert_eq!(Some(&1), counts.get(&'m'));
+    /// ```
+    fn counts_by<K: Eq + Hash, F: FnMut(&Self::Item) -> K>(self, key: F) -> HashMap<K, usize>;
+
+    /// Consumes the iterator and tallies how many times each distinct item occurs.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// let counts = vec![1, 2, 2, 3, 3, 3].into_iter().counts();
+    /// assert_eq!(Some(&1), counts.get(&1));
+    /// assert_eq!(Some(&3), counts.get(&3));
+    /// ```
+    fn counts(self) -> HashMap<Self::Item, usize> where Self::Item: Eq + Hash;
+
+    /// Consumes the iterator and returns the single item passing the predicate, short-circuiting as
+    /// soon as a second passing item is seen. Returns [`ExactlyOneError::Zero`] if nothing matched
+    /// and [`ExactlyOneError::MoreThanOne`] (carrying the first two matches) if more than one did.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// let one_even = vec![1, 2, 3, 5];
+    /// assert_eq!(Ok(2), one_even.into_iter().exactly_one(|n| *n % 2 == 0));
+    /// let no_even = vec![1, 3, 5];
+    /// assert_eq!(Err(ExactlyOneError::Zero), no_even.into_iter().exactly_one(|n| *n % 2 == 0));
+    /// let many_even = vec![2, 4, 6];
+    /// assert_eq!(Err(ExactlyOneError::MoreThanOne(2, 4)), many_even.into_iter().exactly_one(|n| *n % 2 == 0));
+    /// ```
+    fn exactly_one<P: FnMut(&Self::Item) -> bool>(self, predicate: P) -> Result<Self::Item, ExactlyOneError<Self::Item>>;
+
+    /// Consumes the iterator and returns its sole item, requiring the whole iterator to have a
+    /// length of exactly one. Short-circuits as soon as a second item is seen. Returns
+    /// [`ExactlyOneError::Zero`] if the iterator was empty and [`ExactlyOneError::MoreThanOne`]
+    /// (carrying the first two items) if it held more than one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// assert_eq!(Ok(42), vec![42].into_iter().the_one());
+    /// assert_eq!(Err(ExactlyOneError::Zero), Vec::<i32>::new().into_iter().the_one());
+    /// assert_eq!(Err(ExactlyOneError::MoreThanOne(1, 2)), vec![1, 2, 3].into_iter().the_one());
+    /// ```
+    fn the_one(self) -> Result<Self::Item, ExactlyOneError<Self::Item>>;
+
+    /// Creates an iterator that lazily merges runs of adjacent items using `f`.
+    ///
+    /// `f` is called with the pending accumulator and the next item: returning `Ok(merged)` folds
+    /// them into a single accumulator that is offered against the following item, while
+    /// `Err((a, b))` emits `a` and restarts accumulation from `b`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// // Run-length compaction of adjacent equal numbers into (value, count) pairs.
+    /// let runs: Vec<_> = vec![1, 1, 2, 3, 3, 3]
+    ///     .into_iter()
+    ///     .map(|n| (n, 1))
+    ///     .coalesce(|(a, na), (b, nb)| if a == b { Ok((a, na + nb)) } else { Err(((a, na), (b, nb))) })
+    ///     .collect();
+    /// assert_eq!(vec![(1, 2), (2, 1), (3, 3)], runs);
+    /// ```
+    fn coalesce<F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, (Self::Item, Self::Item)>>(self, f: F) -> Coalesce<Self, F>;
+
+    /// Consumes the iterator, reducing it with `f` by combining adjacent pairs into a balanced tree
+    /// rather than the left-leaning chain of [`Iterator::reduce`]. The logarithmic combine depth
+    /// keeps accumulated floating-point rounding error small and yields shallow expression trees.
+    ///
+    /// Pairs are combined left-to-right (`f([0], [1])`, `f([2], [3])`, ...) with any odd element
+    /// carried forward unchanged, so `f` may be non-commutative as long as it is associative.
+    /// Returns `None` for an empty iterator.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// assert_eq!(Some(10), vec![1, 2, 3, 4].into_iter().tree_fold1(|a, b| a + b));
+    /// assert_eq!(None, Vec::<i32>::new().into_iter().tree_fold1(|a, b| a + b));
+    /// ```
+    fn tree_fold1<F: FnMut(Self::Item, Self::Item) -> Self::Item>(self, f: F) -> Option<Self::Item>;
 }
 
 impl<T: Iterator> ExoticIteratorExt for T {    
@@ -182,4 +341,152 @@ impl<T: Iterator> ExoticIteratorExt for T {
     fn alternate<U: IntoIterator<Item = Self::Item>>(self, other: U) -> Alternate<Self, <U as IntoIterator>::IntoIter> {
         Alternate::new(self, other.into_iter())
     }
+
+    fn round_robin<U: IntoIterator<Item = Self>>(self, others: U) -> RoundRobin<Self> {
+        let mut iters = Vec::new();
+        iters.push(self);
+        iters.extend(others);
+        RoundRobin::new(iters)
+    }
+
+    fn max_set_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(self, f: F) -> Vec<Self::Item> {
+        let mut f = f;
+        let mut best: Option<K> = None;
+        let mut set = Vec::new();
+        for item in self {
+            let key = f(&item);
+            match best {
+                Some(ref best_key) if key < *best_key => continue,
+                Some(ref best_key) if key == *best_key => {}
+                _ => {
+                    set.clear();
+                    best = Some(key);
+                }
+            }
+            set.push(item);
+        }
+        set
+    }
+
+    fn min_set_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(self, f: F) -> Vec<Self::Item> {
+        let mut f = f;
+        let mut best: Option<K> = None;
+        let mut set = Vec::new();
+        for item in self {
+            let key = f(&item);
+            match best {
+                Some(ref best_key) if key > *best_key => continue,
+                Some(ref best_key) if key == *best_key => {}
+                _ => {
+                    set.clear();
+                    best = Some(key);
+                }
+            }
+            set.push(item);
+        }
+        set
+    }
+
+    fn max_set_by<F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering>(self, compare: F) -> Vec<Self::Item> {
+        let mut compare = compare;
+        let mut set: Vec<Self::Item> = Vec::new();
+        for item in self {
+            if let Some(best) = set.first() {
+                match compare(&item, best) {
+                    core::cmp::Ordering::Less => continue,
+                    core::cmp::Ordering::Equal => {}
+                    core::cmp::Ordering::Greater => set.clear(),
+                }
+            }
+            set.push(item);
+        }
+        set
+    }
+
+    fn min_set_by<F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering>(self, compare: F) -> Vec<Self::Item> {
+        let mut compare = compare;
+        let mut set: Vec<Self::Item> = Vec::new();
+        for item in self {
+            if let Some(best) = set.first() {
+                match compare(&item, best) {
+                    core::cmp::Ordering::Greater => continue,
+                    core::cmp::Ordering::Equal => {}
+                    core::cmp::Ordering::Less => set.clear(),
+                }
+            }
+            set.push(item);
+        }
+        set
+    }
+
+    fn counts_by<K: Eq + Hash, F: FnMut(&Self::Item) -> K>(self, key: F) -> HashMap<K, usize> {
+        let mut key = key;
+        let mut counts = HashMap::new();
+        for item in self {
+            *counts.entry(key(&item)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    fn counts(self) -> HashMap<Self::Item, usize> where Self::Item: Eq + Hash {
+        let mut counts = HashMap::new();
+        for item in self {
+            *counts.entry(item).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    fn exactly_one<P: FnMut(&Self::Item) -> bool>(self, predicate: P) -> Result<Self::Item, ExactlyOneError<Self::Item>> {
+        let mut predicate = predicate;
+        let mut first: Option<Self::Item> = None;
+        for item in self {
+            if predicate(&item) {
+                match first.take() {
+                    None => first = Some(item),
+                    Some(previous) => return Err(ExactlyOneError::MoreThanOne(previous, item)),
+                }
+            }
+        }
+        first.ok_or(ExactlyOneError::Zero)
+    }
+
+    fn the_one(self) -> Result<Self::Item, ExactlyOneError<Self::Item>> {
+        let mut iter = self;
+        let first = match iter.next() {
+            Some(first) => first,
+            None => return Err(ExactlyOneError::Zero),
+        };
+        match iter.next() {
+            Some(second) => Err(ExactlyOneError::MoreThanOne(first, second)),
+            None => Ok(first),
+        }
+    }
+
+    fn coalesce<F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, (Self::Item, Self::Item)>>(self, f: F) -> Coalesce<Self, F> {
+        Coalesce::new(self, f)
+    }
+
+    fn tree_fold1<F: FnMut(Self::Item, Self::Item) -> Self::Item>(self, f: F) -> Option<Self::Item> {
+        let mut f = f;
+        let mut buf: Vec<Self::Item> = self.collect();
+        if buf.is_empty() {
+            return None;
+        }
+        while buf.len() > 1 {
+            let mut next = Vec::with_capacity((buf.len() + 1) / 2);
+            let mut drain = buf.into_iter();
+            loop {
+                let a = match drain.next() {
+                    Some(a) => a,
+                    None => break,
+                };
+                match drain.next() {
+                    Some(b) => next.push(f(a, b)),
+                    None => next.push(a),
+                }
+            }
+            buf = next;
+        }
+        buf.into_iter().next()
+    }
 }
\ No newline at end of file
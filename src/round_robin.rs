@@ -0,0 +1,85 @@
+/// An iterator that yields items from several iterators in turn, one from each.
+///
+/// See [`ExoticIteratorExt::round_robin`](crate::ExoticIteratorExt::round_robin) for details.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RoundRobin<I> {
+    iters: Vec<I>,
+    done: Vec<bool>,
+    cursor: usize,
+    live: usize,
+    exhausting: bool,
+    short_circuited: bool,
+}
+
+impl<I: Iterator> RoundRobin<I> {
+    pub(crate) fn new(iters: Vec<I>) -> Self {
+        let live = iters.len();
+        let done = vec![false; iters.len()];
+        Self {
+            iters,
+            done,
+            cursor: 0,
+            live,
+            exhausting: false,
+            short_circuited: false,
+        }
+    }
+
+    /// Switches the adapter into *exhausting* mode, in which iteration continues until every input
+    /// has been drained, skipping over inputs that have already ended instead of stopping at the
+    /// first `None`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use exotic_iter::*;
+    /// let a = vec![1, 4];
+    /// let b = vec![2, 5, 7, 8];
+    /// let c = vec![3, 6];
+    /// let items: Vec<_> = a.into_iter().round_robin(vec![b.into_iter(), c.into_iter()]).exhausting().collect();
+    /// assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8], items);
+    /// ```
+    pub fn exhausting(mut self) -> Self {
+        self.exhausting = true;
+        self
+    }
+}
+
+impl<I: Iterator> Iterator for RoundRobin<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iters.is_empty() {
+            return None;
+        }
+
+        if self.exhausting {
+            while self.live > 0 {
+                let index = self.cursor;
+                self.cursor = (self.cursor + 1) % self.iters.len();
+                if self.done[index] {
+                    continue;
+                }
+                match self.iters[index].next() {
+                    Some(item) => return Some(item),
+                    None => {
+                        self.done[index] = true;
+                        self.live -= 1;
+                    }
+                }
+            }
+            None
+        } else {
+            if self.short_circuited {
+                return None;
+            }
+            let index = self.cursor;
+            self.cursor = (self.cursor + 1) % self.iters.len();
+            let item = self.iters[index].next();
+            if item.is_none() {
+                self.short_circuited = true;
+            }
+            item
+        }
+    }
+}